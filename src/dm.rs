@@ -0,0 +1,46 @@
+use mastodon_async::prelude::{AccountId, Status, Visibility};
+
+/// A direct-message conversation, identified by its full participant set
+/// rather than just the two endpoints. A `direct` status that mentions more
+/// people than expected still resolves to one channel, because the whole set
+/// is the key rather than just the first two accounts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DmChannel {
+    pub participants: Vec<AccountId>,
+}
+
+impl DmChannel {
+    pub fn new(mut participants: Vec<AccountId>) -> Self {
+        participants.sort_by_key(|id| id.to_string());
+        participants.dedup();
+        Self { participants }
+    }
+
+    /// Stable key for this channel, independent of participant order.
+    pub fn key(&self) -> String {
+        self.participants
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// Derives the channel a `direct` status belongs to: the mentioned
+    /// accounts plus the author, minus the logged-in user. Returns `None` for
+    /// any status that isn't `direct`.
+    pub fn from_status(status: &Status, me: &AccountId) -> Option<Self> {
+        if status.visibility != Visibility::Direct {
+            return None;
+        }
+
+        let mut participants: Vec<AccountId> = status
+            .mentions
+            .iter()
+            .map(|mention| mention.id.clone())
+            .collect();
+        participants.push(status.account.id.clone());
+        participants.retain(|id| id != me);
+
+        Some(Self::new(participants))
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashSet;
+
+use cosmic_config::Config;
+use mastodon_async::prelude::Status;
+use serde::{Deserialize, Serialize};
+
+/// Config version this lives under in cosmic-config, alongside the rest of the
+/// app's persisted settings.
+pub const CONFIG_VERSION: u64 = 1;
+
+/// Settings-backed allow/deny list for [`Status::language`] filtering, editable
+/// from the account/settings UI and persisted across launches via cosmic-config.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LanguageFilter {
+    Allow(HashSet<String>),
+    Deny(HashSet<String>),
+}
+
+impl Default for LanguageFilter {
+    fn default() -> Self {
+        Self::Deny(HashSet::new())
+    }
+}
+
+impl LanguageFilter {
+    pub fn allows(&self, code: &str) -> bool {
+        match self {
+            Self::Allow(codes) => codes.contains(code),
+            Self::Deny(codes) => !codes.contains(code),
+        }
+    }
+
+    pub fn codes_mut(&mut self) -> &mut HashSet<String> {
+        match self {
+            Self::Allow(codes) | Self::Deny(codes) => codes,
+        }
+    }
+
+    pub fn is_allow_list(&self) -> bool {
+        matches!(self, Self::Allow(_))
+    }
+
+    pub fn set_allow_list(&mut self, is_allow: bool) {
+        let codes = std::mem::take(self.codes_mut());
+        *self = if is_allow {
+            Self::Allow(codes)
+        } else {
+            Self::Deny(codes)
+        };
+    }
+
+    /// Loads the persisted filter, falling back to the default (deny nothing)
+    /// if this is the first launch or the config is unreadable.
+    pub fn load(config: &Config) -> Self {
+        match config.get("language_filter") {
+            Ok(filter) => filter,
+            Err(err) => {
+                tracing::error!("{err}");
+                Self::default()
+            }
+        }
+    }
+
+    /// Persists the filter so it survives across launches.
+    pub fn save(&self, config: &Config) {
+        if let Err(err) = config.set("language_filter", self) {
+            tracing::error!("{err}");
+        }
+    }
+}
+
+/// The language a status should be filtered and badged by: Mastodon's own
+/// `language` field when present, otherwise a best-effort guess.
+pub fn status_language(status: &Status) -> Option<String> {
+    status.language.clone().or_else(|| {
+        let plain = html2text::config::rich()
+            .string_from_read(status.content.as_bytes(), 700)
+            .ok()?;
+        guess_language(&plain)
+    })
+}
+
+/// Drops statuses whose language (declared or guessed) is rejected by `filter`.
+/// A status with no detectable language at all is kept rather than dropped.
+pub fn filter_statuses<'a>(statuses: &'a [Status], filter: &LanguageFilter) -> Vec<&'a Status> {
+    statuses
+        .iter()
+        .filter(|status| {
+            status_language(status)
+                .map(|code| filter.allows(&code))
+                .unwrap_or(true)
+        })
+        .collect()
+}
+
+/// Reference trigrams for the languages `toot` bothers to guess, most common
+/// first. Not a real language model, just enough to bucket obvious cases when
+/// Mastodon doesn't supply a `language` tag.
+const PROFILES: &[(&str, &[&str])] = &[
+    ("en", &[" th", "the", "he ", "ing", " to", "and", "ion"]),
+    ("es", &[" de", "de ", " la", "que", "ent", " el", "ció"]),
+    ("fr", &[" de", "es ", "ent", " le", "que", "ion", " la"]),
+    ("de", &["en ", "der", " de", "ich", "sch", " di", "die"]),
+    ("pt", &[" de", "de ", "ent", " qu", " co", "ção", "ade"]),
+];
+
+fn guess_language(text: &str) -> Option<String> {
+    let text = text.to_lowercase();
+    if text.trim().is_empty() {
+        return None;
+    }
+
+    let trigrams: Vec<String> = text
+        .chars()
+        .collect::<Vec<_>>()
+        .windows(3)
+        .map(|w| w.iter().collect())
+        .collect();
+    if trigrams.is_empty() {
+        return None;
+    }
+
+    PROFILES
+        .iter()
+        .map(|(code, profile)| {
+            let score = trigrams
+                .iter()
+                .filter(|trigram| profile.contains(&trigram.as_str()))
+                .count();
+            (*code, score)
+        })
+        .filter(|(_, score)| *score > 0)
+        .max_by_key(|(_, score)| *score)
+        .map(|(code, _)| code.to_string())
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+
+use cosmic::{widget, Element, Task};
+use mastodon_async::{
+    prelude::{Account, AccountId, Status, StatusBuilder, StatusId, Visibility},
+    Mastodon,
+};
+
+use crate::{
+    app,
+    dm::DmChannel,
+    widgets::status::{self, StatusHandles, StatusState},
+};
+
+/// Per-conversation UI state owned by the parent view: which channel is open
+/// and what's been typed into its compose box so far. Kept here rather than
+/// in `Message` because `update()` needs to mutate it across multiple
+/// messages (open a channel, type a draft, then send it).
+#[derive(Debug, Clone, Default)]
+pub struct DmState {
+    pub active: Option<DmChannel>,
+    pub draft: String,
+}
+
+#[derive(Debug, Clone)]
+pub enum Message {
+    OpenChannel(DmChannel),
+    ComposeInput(String),
+    Send,
+    Sent(Result<Status, String>),
+    Status(status::Message),
+}
+
+/// The conversations list: one row per [`DmChannel`], labelled by its
+/// participants' display names.
+pub fn conversations<'a>(
+    channels: &'a [DmChannel],
+    accounts: &'a HashMap<AccountId, Account>,
+) -> Element<'a, Message> {
+    let spacing = cosmic::theme::active().cosmic().spacing;
+
+    let rows = channels.iter().map(|channel| {
+        let names = channel
+            .participants
+            .iter()
+            .filter_map(|id| accounts.get(id))
+            .map(|account| account.display_name.clone())
+            .collect::<Vec<_>>()
+            .join(", ");
+        widget::button::custom(widget::text(names))
+            .width(cosmic::iced::Length::Fill)
+            .on_press(Message::OpenChannel(channel.clone()))
+            .into()
+    });
+
+    widget::column().extend(rows).spacing(spacing.space_xs).into()
+}
+
+/// A single channel's messages, reusing the [`status`] widget for each one,
+/// plus a compose box that posts with `direct` visibility.
+pub fn thread<'a>(
+    messages: &'a [Status],
+    handles: &'a HashMap<StatusId, StatusHandles>,
+    states: &'a HashMap<StatusId, StatusState>,
+    state: &'a DmState,
+) -> Element<'a, Message> {
+    let spacing = cosmic::theme::active().cosmic().spacing;
+    let fallback_handles = StatusHandles::new(None, None);
+
+    let rows = messages.iter().map(|message| {
+        let handles = handles.get(&message.id).unwrap_or(&fallback_handles);
+        let status_state = states.get(&message.id).copied().unwrap_or_default();
+        status::status(message, handles, status_state)
+            .map(Message::Status)
+            .into()
+    });
+
+    let compose = widget::row()
+        .push(
+            widget::text_input("Write a message...", &state.draft)
+                .on_input(Message::ComposeInput)
+                .on_submit(Message::Send)
+                .width(cosmic::iced::Length::Fill),
+        )
+        .push(widget::button::suggested("Send").on_press(Message::Send))
+        .spacing(spacing.space_xs);
+
+    widget::column()
+        .extend(rows)
+        .push(compose)
+        .spacing(spacing.space_xs)
+        .into()
+}
+
+/// Mentions every other participant by `acct` so the reply threads into the
+/// same channel no matter which participant Mastodon shows it to.
+fn mention_text(channel: &DmChannel, accounts: &HashMap<AccountId, Account>) -> String {
+    channel
+        .participants
+        .iter()
+        .filter_map(|id| accounts.get(id))
+        .map(|account| format!("@{} ", account.acct))
+        .collect()
+}
+
+pub fn update(
+    message: Message,
+    state: &mut DmState,
+    client: &Mastodon,
+    accounts: &HashMap<AccountId, Account>,
+) -> Task<app::Message> {
+    match message {
+        Message::OpenChannel(channel) => {
+            state.active = Some(channel);
+            state.draft.clear();
+            Task::none()
+        }
+        Message::ComposeInput(draft) => {
+            state.draft = draft;
+            Task::none()
+        }
+        Message::Send => {
+            let Some(channel) = state.active.clone() else {
+                return Task::none();
+            };
+            let draft = std::mem::take(&mut state.draft);
+            if draft.trim().is_empty() {
+                return Task::none();
+            }
+
+            let text = format!("{}{draft}", mention_text(&channel, accounts));
+            let client = client.clone();
+            Task::perform(
+                async move {
+                    let status = StatusBuilder::new()
+                        .status(text)
+                        .visibility(Visibility::Direct)
+                        .build()
+                        .map_err(|err| err.to_string())?;
+                    client.new_status(status).await.map_err(|err| err.to_string())
+                },
+                |result| app::Message::Dm(Message::Sent(result)),
+            )
+        }
+        Message::Sent(Ok(_)) => Task::none(),
+        Message::Sent(Err(err)) => {
+            tracing::error!("{err}");
+            Task::none()
+        }
+        // Reply/favorite/boost/bookmark on a DM bubble are the same actions as
+        // on a timeline status; the app handles `status::Message` the same
+        // way regardless of which view it came from.
+        Message::Status(message) => Task::done(app::Message::Dm(Message::Status(message))),
+    }
+}
@@ -0,0 +1,82 @@
+use cosmic_config::Config;
+
+use cosmic::{widget, Element};
+
+use crate::lang::LanguageFilter;
+
+/// Settings-page controls for editing the [`LanguageFilter`]: allow/deny mode
+/// plus an editable list of language codes. Saved to cosmic-config on every
+/// change so the filter survives across launches.
+#[derive(Debug, Clone)]
+pub enum Message {
+    SetAllowList(bool),
+    CodeInput(String),
+    AddCode,
+    RemoveCode(String),
+}
+
+pub fn settings<'a>(filter: &'a LanguageFilter, draft: &'a str) -> Element<'a, Message> {
+    let spacing = cosmic::theme::active().cosmic().spacing;
+
+    let mode = widget::row()
+        .push(widget::text("Only show listed languages"))
+        .push(widget::toggler(filter.is_allow_list()).on_toggle(Message::SetAllowList))
+        .spacing(spacing.space_xs);
+
+    let codes = match filter {
+        LanguageFilter::Allow(codes) | LanguageFilter::Deny(codes) => codes,
+    };
+    let mut sorted: Vec<&String> = codes.iter().collect();
+    sorted.sort();
+
+    let list = widget::column()
+        .extend(sorted.into_iter().map(|code| {
+            widget::row()
+                .push(widget::text(code.to_uppercase()))
+                .push(
+                    widget::button::icon(widget::icon::from_name("edit-delete-symbolic"))
+                        .on_press(Message::RemoveCode(code.clone())),
+                )
+                .spacing(spacing.space_xs)
+                .into()
+        }))
+        .spacing(spacing.space_xxs);
+
+    let add = widget::row()
+        .push(
+            widget::text_input("Language code (e.g. en)", draft)
+                .on_input(Message::CodeInput)
+                .on_submit(Message::AddCode),
+        )
+        .push(widget::button::suggested("Add").on_press(Message::AddCode))
+        .spacing(spacing.space_xs);
+
+    widget::settings::section()
+        .title("Language filter")
+        .add(mode)
+        .add(list)
+        .add(add)
+        .into()
+}
+
+/// Applies a settings-page edit to `filter`, persisting the result.
+pub fn update(message: Message, filter: &mut LanguageFilter, draft: &mut String, config: &Config) {
+    match message {
+        Message::SetAllowList(is_allow) => filter.set_allow_list(is_allow),
+        Message::CodeInput(text) => {
+            *draft = text;
+            return;
+        }
+        Message::AddCode => {
+            let code = draft.trim().to_lowercase();
+            if !code.is_empty() {
+                filter.codes_mut().insert(code);
+            }
+            draft.clear();
+        }
+        Message::RemoveCode(code) => {
+            filter.codes_mut().remove(&code);
+        }
+    }
+    filter.save(config);
+}
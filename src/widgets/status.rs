@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use cosmic::{
     iced::mouse::Interaction,
@@ -6,10 +6,27 @@ use cosmic::{
     widget::{self, image::Handle},
     Element,
 };
-use mastodon_async::prelude::{Notification, Status, StatusId};
+use mastodon_async::{
+    prelude::{AccountId, Context, Mastodon, Notification, Status, StatusId},
+    Result as MastodonResult,
+};
 
 use crate::utils;
 
+/// The root view the app is currently showing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FeedKind {
+    Timeline,
+    Thread(StatusId),
+    Profile(AccountId),
+}
+
+/// Fetches `GET /api/v1/statuses/:id/context`, the ancestors/descendants pair
+/// that [`thread`] renders around a focused status.
+pub async fn fetch_context(client: &Mastodon, id: &StatusId) -> MastodonResult<Context> {
+    client.get_context(id).await
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct StatusHandles {
     pub primary: Option<Handle>,
@@ -27,7 +44,7 @@ impl StatusHandles {
     }
 
     pub fn from_status(status: &Status, handles: &HashMap<String, Handle>) -> Self {
-        let (primary, secondary, media) = (
+        let (primary, secondary, mut media) = (
             handles.get(&status.account.avatar.to_string()),
             status
                 .reblog
@@ -46,8 +63,19 @@ impl StatusHandles {
                             .unwrap_or(utils::fallback_handle()),
                     )
                 })
-                .collect(),
+                .collect::<HashMap<_, _>>(),
         );
+
+        if let Some(image) = status.card.as_ref().and_then(|card| card.image.as_ref()) {
+            media.insert(
+                image.clone(),
+                handles
+                    .get(image)
+                    .cloned()
+                    .unwrap_or(utils::fallback_handle()),
+            );
+        }
+
         Self {
             primary: primary.cloned(),
             secondary: secondary.cloned(),
@@ -93,18 +121,111 @@ impl StatusHandles {
     }
 }
 
+/// Per-status UI state that the `status()` widget itself has no way to hold,
+/// since it's a stateless render function. Kept by the parent view, keyed by
+/// [`StatusId`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatusState {
+    pub spoiler_expanded: bool,
+    pub media_revealed: bool,
+}
+
 #[derive(Debug, Clone)]
 pub enum Message {
     OpenProfile(String),
-    ExpandStatus(Status),
+    OpenThread(StatusId),
     Reply(StatusId),
     Favorite(StatusId),
     Boost(StatusId),
     Bookmark(StatusId),
     OpenLink(String),
+    ToggleSpoiler(StatusId),
+    RevealMedia(StatusId),
+}
+
+impl Message {
+    /// The root view a tap on this message should switch the app to, if any.
+    /// Callers fetch [`fetch_context`] for a `Thread` switch before rendering
+    /// [`thread`].
+    pub fn feed_kind(&self) -> Option<FeedKind> {
+        match self {
+            Message::OpenThread(id) => Some(FeedKind::Thread(id.clone())),
+            _ => None,
+        }
+    }
 }
 
-pub fn status<'a>(status: &Status, handles: &StatusHandles) -> Element<'a, Message> {
+/// Renders `status.content` with `@mentions` and inline `#hashtags` kept
+/// clickable, instead of flattening the HTML to dead plain text. Mastodon
+/// gives a profile mention the class `u-url mention` and a hashtag link the
+/// class `mention hashtag`, so `mention` alone doesn't disambiguate them:
+/// anchors classed `hashtag` (or whose href looks like `/tags/...`) open the
+/// href directly, anchors classed `mention` without that open the mentioned
+/// profile, any other link opens its href, and non-link text renders as plain
+/// text.
+fn render_body<'a>(status: &Status) -> Element<'a, Message> {
+    use html2text::render::RichAnnotation;
+
+    let spacing = cosmic::theme::active().cosmic().spacing;
+    let lines = html2text::from_read_rich(status.content.as_bytes(), 700).unwrap_or_default();
+
+    let rows = lines
+        .into_iter()
+        .map(|line| {
+            let segments = line
+                .tagged_strings()
+                .map(|tagged| {
+                    let href = tagged.tag.iter().find_map(|annotation| match annotation {
+                        RichAnnotation::Link(href) => Some(href.clone()),
+                        _ => None,
+                    });
+                    // Split on whitespace so this works whether html2text hands
+                    // back one `Class` annotation per token or the whole
+                    // `class="..."` attribute as a single string.
+                    let classes: Vec<&str> = tagged
+                        .tag
+                        .iter()
+                        .flat_map(|annotation| match annotation {
+                            RichAnnotation::Class(class) => class.split_whitespace().collect(),
+                            _ => Vec::new(),
+                        })
+                        .collect();
+                    let is_hashtag = classes.contains(&"hashtag")
+                        || href.as_deref().is_some_and(|href| href.contains("/tags/"));
+                    let is_mention = classes.contains(&"mention") && !is_hashtag;
+
+                    match href {
+                        Some(href) if is_mention => widget::button::link(tagged.s.clone())
+                            .on_press(Message::OpenProfile(href))
+                            .into(),
+                        Some(href) => widget::button::link(tagged.s.clone())
+                            .on_press(Message::OpenLink(href))
+                            .into(),
+                        // Only plain (non-link) text opens the thread. Wrapping
+                        // the whole row in one `MouseArea` would sit on top of
+                        // the link buttons above and risk swallowing their
+                        // clicks, so each plain run gets its own small
+                        // `MouseArea` instead.
+                        None => widget::MouseArea::new(widget::text(tagged.s.clone()))
+                            .interaction(Interaction::Pointer)
+                            .on_press(Message::OpenThread(status.id.clone()))
+                            .into(),
+                    }
+                })
+                .collect::<Vec<Element<Message>>>();
+
+            widget::row().extend(segments).into()
+        })
+        .collect::<Vec<Element<Message>>>();
+
+    widget::column().extend(rows).spacing(spacing.space_xxs).into()
+}
+
+pub fn status<'a>(
+    status: &Status,
+    handles: &StatusHandles,
+    state: StatusState,
+) -> Element<'a, Message> {
     let spacing = cosmic::theme::active().cosmic().spacing;
     let (status_avatar, reblog_avatar) = if status.reblog.is_some() {
         (handles.secondary.clone(), handles.primary.clone())
@@ -135,6 +256,26 @@ pub fn status<'a>(status: &Status, handles: &StatusHandles) -> Element<'a, Messa
         status.account.display_name, status.account.username
     );
 
+    let has_spoiler = !status.spoiler_text.is_empty();
+    let body_visible = !has_spoiler || state.spoiler_expanded;
+
+    let spoiler: Option<Element<_>> = has_spoiler.then(|| {
+        widget::row()
+            .push(widget::text(status.spoiler_text.clone()))
+            .push(
+                widget::button::standard(if state.spoiler_expanded {
+                    "Show less"
+                } else {
+                    "Show more"
+                })
+                .on_press(Message::ToggleSpoiler(status.id.clone())),
+            )
+            .spacing(spacing.space_xs)
+            .into()
+    });
+
+    let body: Option<Element<_>> = body_visible.then(|| render_body(status));
+
     let content = widget::row()
         .push(
             widget::button::image(status_avatar.unwrap_or(crate::utils::fallback_handle()))
@@ -145,18 +286,19 @@ pub fn status<'a>(status: &Status, handles: &StatusHandles) -> Element<'a, Messa
         .push(
             widget::column()
                 .push(
-                    widget::button::link(display_name)
-                        .on_press(Message::OpenProfile(status.account.url.clone())),
-                )
-                .push(
-                    widget::MouseArea::new(widget::text(
-                        html2text::config::rich()
-                            .string_from_read(status.content.as_bytes(), 700)
-                            .unwrap(),
-                    ))
-                    .interaction(Interaction::Pointer)
-                    .on_press(Message::ExpandStatus(status.clone())),
+                    widget::row()
+                        .push(
+                            widget::button::link(display_name)
+                                .on_press(Message::OpenProfile(status.account.url.clone())),
+                        )
+                        .push_maybe(
+                            crate::lang::status_language(status)
+                                .map(|code| widget::text::caption(code.to_uppercase())),
+                        )
+                        .spacing(spacing.space_xs),
                 )
+                .push_maybe(spoiler)
+                .push_maybe(body)
                 .spacing(spacing.space_xxs),
         )
         .spacing(spacing.space_xs);
@@ -194,10 +336,56 @@ pub fn status<'a>(status: &Status, handles: &StatusHandles) -> Element<'a, Messa
         .collect::<Vec<Element<Message>>>();
 
     let media = (!status.media_attachments.is_empty()).then_some({
-        widget::scrollable(widget::row().extend(attachments).spacing(spacing.space_xxs))
-            .direction(Direction::Horizontal(Scrollbar::new()))
+        if status.sensitive && !state.media_revealed {
+            widget::container(
+                widget::button::custom(widget::text("Sensitive content"))
+                    .on_press(Message::RevealMedia(status.id.clone())),
+            )
+        } else {
+            widget::container(
+                widget::scrollable(widget::row().extend(attachments).spacing(spacing.space_xxs))
+                    .direction(Direction::Horizontal(Scrollbar::new())),
+            )
+        }
     });
 
+    let card: Option<Element<_>> = status.card.as_ref().map(|card| {
+        let image = card
+            .image
+            .as_ref()
+            .and_then(|image| handles.media.get(image))
+            .cloned();
+        widget::button::custom(
+            widget::row()
+                .push_maybe(image.map(|handle| widget::image(handle).width(80).height(80)))
+                .push(
+                    widget::column()
+                        .push(
+                            widget::text(card.title.clone())
+                                .size(16)
+                                .font(cosmic::font::bold()),
+                        )
+                        .push(widget::text::caption(card.provider_name.clone()))
+                        .push(widget::text::caption(truncate(&card.description, 140)))
+                        .spacing(spacing.space_xxs),
+                )
+                .spacing(spacing.space_xs),
+        )
+        .class(cosmic::style::Button::Icon)
+        .on_press(Message::OpenLink(card.url.clone()))
+        .into()
+    });
+    let card = card.map(|card| widget::container(card).class(cosmic::style::Container::Card));
+
+    fn truncate(text: &str, max_chars: usize) -> String {
+        if text.chars().count() <= max_chars {
+            return text.to_string();
+        }
+        let mut truncated: String = text.chars().take(max_chars).collect();
+        truncated.push('…');
+        truncated
+    }
+
     let actions = widget::row()
         .push(
             widget::button::icon(widget::icon::from_name("mail-replied-symbolic"))
@@ -231,10 +419,96 @@ pub fn status<'a>(status: &Status, handles: &StatusHandles) -> Element<'a, Messa
         .push(content)
         .push_maybe(media)
         .push_maybe(tags)
+        .push_maybe(card)
         .push(actions)
         .spacing(spacing.space_xs);
 
     widget::settings::flex_item_row(vec![status.into()])
         .padding(spacing.space_xs)
         .into()
+}
+
+/// Renders a [`FeedKind::Thread`]: the focused status with its ancestors stacked
+/// above it and its descendants nested below, indented by reply depth.
+///
+/// Descendants are linked to their parent via `in_reply_to_id`; a descendant whose
+/// parent isn't present in `descendants` or the focused status is attached directly
+/// below the focused status at depth 1. Already-visited ids are tracked so a
+/// malformed `in_reply_to_id` chain can't recurse forever.
+pub fn thread<'a>(
+    focused: &Status,
+    ancestors: &[Status],
+    descendants: &[Status],
+    handles: &HashMap<StatusId, StatusHandles>,
+    states: &HashMap<StatusId, StatusState>,
+) -> Element<'a, Message> {
+    let spacing = cosmic::theme::active().cosmic().spacing;
+    let fallback_handles = StatusHandles::new(None, None);
+
+    let row = |s: &Status, depth: u16| {
+        let handles = handles.get(&s.id).unwrap_or(&fallback_handles);
+        let state = states.get(&s.id).copied().unwrap_or_default();
+        widget::container(status(s, handles, state))
+            .padding([0, 0, 0, depth * spacing.space_m])
+            .into()
+    };
+
+    let mut column = widget::column().spacing(spacing.space_xs);
+    for ancestor in ancestors {
+        column = column.push(row(ancestor, 0));
+    }
+    column = column.push(row(focused, 0));
+
+    // Anything whose parent isn't the focused status or another descendant
+    // (including a parent id we simply weren't sent) gets attached directly
+    // under the focused status instead, per the spec.
+    let known_ids: HashSet<StatusId> = descendants
+        .iter()
+        .map(|s| s.id.clone())
+        .chain(std::iter::once(focused.id.clone()))
+        .collect();
+
+    let children: HashMap<StatusId, Vec<&Status>> =
+        descendants.iter().fold(HashMap::new(), |mut map, status| {
+            let parent = status
+                .in_reply_to_id
+                .clone()
+                .filter(|id| known_ids.contains(id))
+                .unwrap_or_else(|| focused.id.clone());
+            map.entry(parent).or_default().push(status);
+            map
+        });
+
+    let mut visited = HashSet::new();
+    visited.insert(focused.id.clone());
+
+    let mut ordered = Vec::new();
+    collect_preorder(&focused.id, 1, &children, &mut visited, &mut ordered);
+    for (reply, depth) in ordered {
+        column = column.push(row(reply, depth));
+    }
+
+    column.into()
+}
+
+/// Walks `children` depth-first, pre-order, so each reply is immediately
+/// followed by its own replies rather than its siblings' subtrees. `visited`
+/// guards against a malformed `in_reply_to_id` cycle recursing forever.
+fn collect_preorder<'a>(
+    parent_id: &StatusId,
+    depth: u16,
+    children: &HashMap<StatusId, Vec<&'a Status>>,
+    visited: &mut HashSet<StatusId>,
+    out: &mut Vec<(&'a Status, u16)>,
+) {
+    let Some(replies) = children.get(parent_id) else {
+        return;
+    };
+    for reply in replies {
+        if !visited.insert(reply.id.clone()) {
+            continue;
+        }
+        out.push((reply, depth));
+        collect_preorder(&reply.id, depth + 1, children, visited, out);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,32 @@
+use std::collections::HashMap;
+
+use cosmic::{widget, Element};
+use mastodon_async::prelude::{Status, StatusId};
+
+use crate::{
+    lang::{filter_statuses, LanguageFilter},
+    widgets::status::{self, StatusHandles, StatusState},
+};
+
+/// Renders a timeline: `statuses` is run through [`filter_statuses`] against
+/// the account's [`LanguageFilter`] before any of it reaches the [`status`]
+/// widget, so filtered-out languages never get rendered at all.
+pub fn timeline<'a>(
+    statuses: &'a [Status],
+    filter: &LanguageFilter,
+    handles: &'a HashMap<StatusId, StatusHandles>,
+    states: &'a HashMap<StatusId, StatusState>,
+) -> Element<'a, status::Message> {
+    let spacing = cosmic::theme::active().cosmic().spacing;
+    let fallback_handles = StatusHandles::new(None, None);
+
+    let rows = filter_statuses(statuses, filter)
+        .into_iter()
+        .map(|status| {
+            let handles = handles.get(&status.id).unwrap_or(&fallback_handles);
+            let state = states.get(&status.id).copied().unwrap_or_default();
+            status::status(status, handles, state)
+        });
+
+    widget::column().extend(rows).spacing(spacing.space_xs).into()
+}